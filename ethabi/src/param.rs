@@ -16,18 +16,41 @@ use alloc::string::String;
 #[cfg(feature = "std")]
 use serde::{
 	de::{Error, MapAccess, Visitor},
-	Deserialize, Deserializer,
+	ser::SerializeMap,
+	Deserialize, Deserializer, Serialize, Serializer,
 };
 #[cfg(feature = "std")]
 use std::fmt;
 
 /// Function param.
+///
+/// `Serialize` round-trips `name`, `type` and `internal_type`, but for a
+/// tuple (or an array/fixed array of one) it does not preserve the names of
+/// its `components`: `ParamType::Tuple` only stores each member's
+/// `ParamType`, having already discarded its original `name`/`internalType`
+/// on the way in, so every emitted component below the top level comes back
+/// out with `"name": ""`. Writing a real-world ABI back out through this
+/// impl will not reproduce its nested struct/tuple field names.
+///
+/// It also only round-trips a single level of array wrapping a tuple
+/// (`tuple[]`, `tuple[2]`): `ParamVisitor` only recognises `Tuple` directly
+/// inside one `Array`/`FixedArray`, so a doubly-wrapped shape like
+/// `tuple[][]` serializes its `components` correctly but silently loses them
+/// (rather than erroring) when deserialized back.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Param {
 	/// Param name.
 	pub name: String,
 	/// Param type.
 	pub kind: ParamType,
+	/// Solidity internal type, e.g. `struct MyLib.Order`, as emitted by solc
+	/// alongside `type`. `None` when the ABI doesn't carry it.
+	///
+	/// Only tracked on this `Param` itself: `internalType` on a nested tuple
+	/// `components` entry (e.g. the struct name of a tuple member) is parsed
+	/// but dropped, since `ParamType::Tuple` only stores each member's
+	/// `ParamType`, not a full `Param`/`TupleParam`.
+	pub internal_type: Option<String>,
 }
 
 impl Param {
@@ -36,6 +59,7 @@ impl Param {
 		Param {
 			name: new_name.into(),
 			kind,
+			internal_type: None,
 		}
 	}
 }
@@ -45,6 +69,7 @@ impl From<(&str, ParamType)> for Param {
 		Param {
 			name: param.0.into(),
 			kind: param.1,
+			internal_type: None,
 		}
 	}
 }
@@ -59,6 +84,137 @@ impl<'a> Deserialize<'a> for Param {
 	}
 }
 
+/// See the caveat on [`Param`] about nested `components` losing their names.
+#[cfg(feature = "std")]
+impl Serialize for Param {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut map = serializer.serialize_map(None)?;
+		if let Some(internal_type) = &self.internal_type {
+			map.serialize_entry("internalType", internal_type)?;
+		}
+		map.serialize_entry("name", &self.name)?;
+		map.serialize_entry("type", &canonical_type_str(&self.kind))?;
+		if let Some(components) = tuple_components(&self.kind) {
+			map.serialize_entry("components", &components)?;
+		}
+		map.end()
+	}
+}
+
+/// Renders the canonical Solidity type string for a `ParamType`, collapsing
+/// `Tuple` (and arrays of it) down to `"tuple"`, `"tuple[]"`, `"tuple[2]"`, ...
+/// the way solc's ABI JSON does.
+#[cfg(feature = "std")]
+fn canonical_type_str(kind: &ParamType) -> String {
+	match kind {
+		ParamType::Tuple(_) => "tuple".to_owned(),
+		ParamType::Array(inner) => format!("{}[]", canonical_type_str(inner)),
+		ParamType::FixedArray(inner, size) => format!("{}[{}]", canonical_type_str(inner), size),
+		_ => kind.to_string(),
+	}
+}
+
+/// Builds the `components` array for a `Param` whose (possibly array-nested)
+/// kind is a tuple, recursing through `Param::serialize` for each member.
+///
+/// Member names are not preserved: `ParamType::Tuple` only stores each
+/// component's `ParamType`, having already discarded its original `name`
+/// (and `internalType`) on the way in through `ParamVisitor`. Every emitted
+/// component below the top level therefore comes back out with `name: ""`,
+/// so round-tripping a real-world ABI through `Param`'s `Serialize` impl does
+/// not reproduce the original component names.
+#[cfg(feature = "std")]
+fn tuple_components(kind: &ParamType) -> Option<Vec<Param>> {
+	match kind {
+		ParamType::Tuple(members) => Some(
+			members
+				.iter()
+				.map(|kind| Param { name: String::new(), kind: kind.clone(), internal_type: None })
+				.collect(),
+		),
+		ParamType::Array(inner) => tuple_components(inner),
+		ParamType::FixedArray(inner, _) => tuple_components(inner),
+		_ => None,
+	}
+}
+
+/// Error returned by [`params_values_by_position`] when a `Param` list and a
+/// by-name JSON object don't line up.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum NamedParamsError {
+	/// `values` had no entry for this `Param::name`.
+	MissingParam(String),
+	/// `values` contained a key that doesn't match any `Param::name`.
+	UnknownParam(String),
+	/// `params` used the same name for more than one parameter, so a single
+	/// by-name object can't say which position a value belongs to.
+	DuplicateParam(String),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for NamedParamsError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			NamedParamsError::MissingParam(name) => write!(f, "missing value for param `{}`", name),
+			NamedParamsError::UnknownParam(name) => write!(f, "unknown param `{}`", name),
+			NamedParamsError::DuplicateParam(name) => {
+				write!(f, "param `{}` is used more than once and can't be matched by name", name)
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NamedParamsError {}
+
+/// Looks up each of `params`'s value by `Param::name` in `values`, returning
+/// the raw JSON values in `params` order.
+///
+/// This is a name-to-position lookup only, not an encoder: it does not
+/// tokenize the values against each `Param::kind`, and it cannot recurse into
+/// tuple `components` by member name, since those names aren't retained past
+/// deserialization (see `tuple_components`). Turning the result of this
+/// function into an ABI-encoded call still requires a tokenizer built on
+/// `Function`/`Token`, which live outside this module and aren't part of
+/// this checkout.
+///
+/// **This does not implement named-parameter call encoding.** The request
+/// that motivated this function (encode a call from a named JSON params
+/// object: tokenize each value against `Param::kind`, recursing into tuple
+/// `components` by member name) stays open/blocked until `Function` and
+/// `Token` are in scope and a real `Function::encode_input`-style tokenizer
+/// can be written against this lookup. Don't read this function's presence
+/// as that request being done.
+///
+/// Returns [`NamedParamsError::DuplicateParam`] if `params` repeats a name
+/// (most commonly several unnamed Solidity params, which all deserialize to
+/// `name: ""`): a single JSON object has at most one value per key, so there
+/// would be no way to tell which position it belongs to.
+#[cfg(feature = "std")]
+pub fn params_values_by_position(
+	params: &[Param],
+	mut values: serde_json::Map<String, serde_json::Value>,
+) -> Result<Vec<serde_json::Value>, NamedParamsError> {
+	let mut seen = std::collections::HashSet::new();
+	for param in params {
+		if !seen.insert(param.name.as_str()) {
+			return Err(NamedParamsError::DuplicateParam(param.name.clone()));
+		}
+	}
+	let ordered = params
+		.iter()
+		.map(|param| values.remove(&param.name).ok_or_else(|| NamedParamsError::MissingParam(param.name.clone())))
+		.collect::<Result<Vec<_>, _>>()?;
+	if let Some(unknown) = values.keys().next() {
+		return Err(NamedParamsError::UnknownParam(unknown.clone()));
+	}
+	Ok(ordered)
+}
+
 #[cfg(feature = "std")]
 struct ParamVisitor;
 
@@ -77,6 +233,7 @@ impl<'a> Visitor<'a> for ParamVisitor {
 		let mut name = None;
 		let mut kind = None;
 		let mut components = None;
+		let mut internal_type = None;
 
 		while let Some(ref key) = map.next_key::<String>()? {
 			match key.as_ref() {
@@ -88,7 +245,7 @@ impl<'a> Visitor<'a> for ParamVisitor {
 				}
 				"type" => {
 					if kind.is_some() {
-						return Err(Error::duplicate_field("kind"));
+						return Err(Error::duplicate_field("type"));
 					}
 					kind = Some(map.next_value()?);
 				}
@@ -99,6 +256,12 @@ impl<'a> Visitor<'a> for ParamVisitor {
 					let component: Vec<TupleParam> = map.next_value()?;
 					components = Some(component)
 				}
+				"internalType" => {
+					if internal_type.is_some() {
+						return Err(Error::duplicate_field("internalType"));
+					}
+					internal_type = Some(map.next_value()?);
+				}
 				_ => {}
 			}
 		}
@@ -130,13 +293,15 @@ impl<'a> Visitor<'a> for ParamVisitor {
 				},
 				_ => Ok(param_type),
 			})?;
-		Ok(Param { name, kind })
+		Ok(Param { name, kind, internal_type })
 	}
 }
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
+	use super::{params_values_by_position, NamedParamsError};
 	use crate::{Param, ParamType};
+	use serde_json::json;
 
 	#[test]
 	fn param_deserialization() {
@@ -147,7 +312,7 @@ mod tests {
 
 		let deserialized: Param = serde_json::from_str(s).unwrap();
 
-		assert_eq!(deserialized, Param { name: "foo".to_owned(), kind: ParamType::Address });
+		assert_eq!(deserialized, Param { name: "foo".to_owned(), kind: ParamType::Address, internal_type: None });
 	}
 
 	#[test]
@@ -180,6 +345,7 @@ mod tests {
 			Param {
 				name: "foo".to_owned(),
 				kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
+				internal_type: None,
 			}
 		);
 	}
@@ -216,6 +382,7 @@ mod tests {
 					ParamType::Address,
 					ParamType::Address
 				]))),
+				internal_type: None,
 			}
 		);
 	}
@@ -251,7 +418,153 @@ mod tests {
 					Box::new(ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Address, ParamType::Address])),
 					2
 				),
+				internal_type: None,
+			}
+		);
+	}
+
+	#[test]
+	fn param_serialization_roundtrip() {
+		let param = Param { name: "foo".to_owned(), kind: ParamType::Address, internal_type: None };
+		let serialized = serde_json::to_string(&param).unwrap();
+		let deserialized: Param = serde_json::from_str(&serialized).unwrap();
+		assert_eq!(param, deserialized);
+	}
+
+	#[test]
+	fn param_tuple_serialization_roundtrip() {
+		let param = Param {
+			name: "foo".to_owned(),
+			kind: ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Tuple(vec![ParamType::Address])]),
+			internal_type: None,
+		};
+		let serialized = serde_json::to_string(&param).unwrap();
+		let deserialized: Param = serde_json::from_str(&serialized).unwrap();
+		assert_eq!(param, deserialized);
+	}
+
+	#[test]
+	fn param_tuple_array_serialization_roundtrip() {
+		let param = Param {
+			name: "foo".to_owned(),
+			kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
+				ParamType::Uint(48),
+				ParamType::Address,
+				ParamType::Address,
+			]))),
+			internal_type: None,
+		};
+		let serialized = serde_json::to_string(&param).unwrap();
+		let deserialized: Param = serde_json::from_str(&serialized).unwrap();
+		assert_eq!(param, deserialized);
+	}
+
+	#[test]
+	fn param_tuple_fixed_array_serialization_roundtrip() {
+		let param = Param {
+			name: "foo".to_owned(),
+			kind: ParamType::FixedArray(
+				Box::new(ParamType::Tuple(vec![ParamType::Uint(48), ParamType::Address, ParamType::Address])),
+				2,
+			),
+			internal_type: None,
+		};
+		let serialized = serde_json::to_string(&param).unwrap();
+		let deserialized: Param = serde_json::from_str(&serialized).unwrap();
+		assert_eq!(param, deserialized);
+	}
+
+	#[test]
+	fn param_internal_type_deserialization() {
+		let s = r#"{
+			"name": "order",
+			"type": "tuple",
+			"internalType": "struct MyLib.Order",
+			"components": [
+				{
+					"name": "amount",
+					"type": "uint48"
+				}
+			]
+		}"#;
+
+		let deserialized: Param = serde_json::from_str(s).unwrap();
+
+		assert_eq!(
+			deserialized,
+			Param {
+				name: "order".to_owned(),
+				kind: ParamType::Tuple(vec![ParamType::Uint(48)]),
+				internal_type: Some("struct MyLib.Order".to_owned()),
 			}
 		);
 	}
+
+	#[test]
+	fn param_internal_type_serialization_roundtrip() {
+		let param = Param {
+			name: "order".to_owned(),
+			kind: ParamType::Tuple(vec![ParamType::Uint(48)]),
+			internal_type: Some("struct MyLib.Order".to_owned()),
+		};
+		let serialized = serde_json::to_string(&param).unwrap();
+		let deserialized: Param = serde_json::from_str(&serialized).unwrap();
+		assert_eq!(param, deserialized);
+	}
+
+	#[test]
+	fn params_values_by_position_reorders_by_name() {
+		let params =
+			vec![Param::new("to", ParamType::Address), Param::new("amount", ParamType::Uint(256))];
+		let values = match json!({"amount": 42, "to": "0x0000000000000000000000000000000000000001"}) {
+			serde_json::Value::Object(map) => map,
+			_ => unreachable!(),
+		};
+
+		let ordered = params_values_by_position(&params, values).unwrap();
+
+		assert_eq!(ordered, vec![json!("0x0000000000000000000000000000000000000001"), json!(42)]);
+	}
+
+	#[test]
+	fn params_values_by_position_rejects_missing_param() {
+		let params = vec![Param::new("to", ParamType::Address)];
+		let values = match json!({}) {
+			serde_json::Value::Object(map) => map,
+			_ => unreachable!(),
+		};
+
+		assert_eq!(
+			params_values_by_position(&params, values),
+			Err(NamedParamsError::MissingParam("to".to_owned()))
+		);
+	}
+
+	#[test]
+	fn params_values_by_position_rejects_unknown_param() {
+		let params = vec![Param::new("to", ParamType::Address)];
+		let values = match json!({"to": "0x1", "extra": 1}) {
+			serde_json::Value::Object(map) => map,
+			_ => unreachable!(),
+		};
+
+		assert_eq!(
+			params_values_by_position(&params, values),
+			Err(NamedParamsError::UnknownParam("extra".to_owned()))
+		);
+	}
+
+	#[test]
+	fn params_values_by_position_rejects_duplicate_param_name() {
+		let params = vec![Param::new("", ParamType::Address), Param::new("", ParamType::Uint(256))];
+		let values = match json!({"": "0x1"}) {
+			serde_json::Value::Object(map) => map,
+			_ => unreachable!(),
+		};
+
+		assert_eq!(
+			params_values_by_position(&params, values),
+			Err(NamedParamsError::DuplicateParam("".to_owned()))
+		);
+	}
 }